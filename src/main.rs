@@ -1,159 +1,624 @@
 pub mod event_bus {
-    use std::{any::Any, collections::HashMap, io::Result};
+    use std::{any::Any, collections::HashMap, hash::Hash, io::Result};
 
     /// Structure for events
     ///
     /// Create a new event using the [Event::new] function. Create listener functions/data structures that use the type [Subscriber] and post events using an [EventBus]
-    /// Different types of events will be tracked with names so keep your spelling and data type consistent (there will be a lot of messy conversion)
-    pub struct Event {
-        name: String,           // Track different events by string
+    /// Events are keyed by the [EventBus]'s `K` type, so pick something that's cheap to clone and consistent: an `enum` gives you compile-time exhaustiveness, a `&'static str` costs no allocation, integers work too.
+    /// An event can also carry a `G` group id via [Event::with_group], so a [Subscriber] registered with [EventBus::subscribe_group] catches it alongside every other event in that group.
+    pub struct Event<K, G = K> {
+        name: K,                // Track different events by key
+        pub group: Option<G>,   // Tag for subscribers listening on a whole group rather than a single name
         pub data: Box<dyn Any>, // Data can be anything, it could even be another boat!
     }
 
     /////////////////////////////////////////////////////////////////////////////
     // Type implementation
     /////////////////////////////////////////////////////////////////////////////
-    impl Event {
+    impl<K, G> Event<K, G> {
         /// Creates a new event with the provided name and data
-        pub fn new<S, D>(name: S, data: D) -> Self
+        pub fn new<D>(name: K, data: D) -> Self
         where
-            S: Into<String>, // Allows use of both Strings and &strs as well as anything else people wanna put as a name
             D: 'static
         {
             Event {
-                name: name.into(),
+                name,
+                group: None,
+                data: Box::new(data),
+            }
+        }
+
+        /// Creates a new event tagged with the given group, so [EventBus::subscribe_group] listeners receive it too
+        pub fn with_group<D>(name: K, group: G, data: D) -> Self
+        where
+            D: 'static
+        {
+            Event {
+                name,
+                group: Some(group),
                 data: Box::new(data),
             }
         }
 
         pub fn data<T: 'static>(&self) -> &T { // If this panics ur fucked
-            let data = &self.data.downcast_ref::<T>().unwrap();
+            self.try_data().unwrap()
+        }
 
-            return data;
+        /// Same as [Event::data], but returns [None] instead of panicking if `T` doesn't match
+        pub fn try_data<T: 'static>(&self) -> Option<&T> {
+            self.data.downcast_ref::<T>()
         }
     }
 
     /// Trait for invoking a function when an [Event] is posted
-    pub trait Subscriber {
+    ///
+    /// Requires [Any] so [EventBus::unsubscribe] can identify a previously-registered [Subscriber] by its concrete type
+    pub trait Subscriber<K, G = K>: Any {
         /// Function that will be called when
-        fn call(&mut self, event: &Event) -> Result<()>;
+        fn call(&mut self, event: &Event<K, G>) -> Result<()>;
+    }
+
+    /// Lets a plain closure act as a [Subscriber], so one-off handlers don't need a named type
+    impl<K, G, F> Subscriber<K, G> for F
+    where
+        F: FnMut(&Event<K, G>) -> Result<()> + 'static,
+    {
+        fn call(&mut self, event: &Event<K, G>) -> Result<()> {
+            self(event)
+        }
+    }
+
+    /// A boxed [Subscriber] together with whether it should be dropped after firing once
+    ///
+    /// `subscriber.type_id()` has to be reached through an explicit deref (rather than calling it straight on the `Box`) so it resolves against the boxed [Subscriber] itself and not `Box<dyn Subscriber<K, G>>` as a type in its own right
+    pub struct Registration<K, G = K> {
+        pub subscriber: Box<dyn Subscriber<K, G>>,
+        pub once: bool,
     }
 
     /// Event Bus structure
     ///
-    /// This will store the names of events and their corresponding [Subscriber] objects
-    pub struct EventBus {
-        event_subscribers: HashMap<String, Vec<Box<dyn Subscriber>>>,
+    /// This will store event keys and their corresponding [Registration]s, as well as group ids and the [Registration]s listening on the whole group
+    pub struct EventBus<K, G = K> {
+        event_subscribers: HashMap<K, Vec<Registration<K, G>>>,
+        group_subscribers: HashMap<G, Vec<Registration<K, G>>>,
     }
 
     /////////////////////////////////////////////////////////////////////////////
     // Type implementation
     /////////////////////////////////////////////////////////////////////////////
-    impl EventBus {
+    impl<K: Eq + Hash + Clone + 'static, G: Eq + Hash + Clone + 'static> Default for EventBus<K, G> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<K: Eq + Hash + Clone + 'static, G: Eq + Hash + Clone + 'static> EventBus<K, G> {
         /// Creates a new [EventBus] with an empty subscriber map
         pub fn new() -> Self {
             EventBus {
                 event_subscribers: HashMap::new(),
+                group_subscribers: HashMap::new(),
             }
         }
 
         /// Creates a new [EventBus] with the provided subscriber list
-        pub fn from(event_subscribers: HashMap<String, Vec<Box<dyn Subscriber>>>) -> Self {
-            EventBus { event_subscribers }
+        pub fn from(event_subscribers: HashMap<K, Vec<Registration<K, G>>>) -> Self {
+            EventBus {
+                event_subscribers,
+                group_subscribers: HashMap::new(),
+            }
+        }
+
+        /// Adds the provided [Subscriber] to the subscriber list of the provided key
+        pub fn subscribe<S>(&mut self, event_name: K, subscriber: S) -> Result<()>
+        where
+            S: Subscriber<K, G> + 'static
+        {
+            self.register(event_name, subscriber, false);
+
+            Ok(())
+        }
+
+        /// Like [EventBus::subscribe], but the [Subscriber] is automatically removed the moment its `call` succeeds during [EventBus::post]
+        pub fn subscribe_once<S>(&mut self, event_name: K, subscriber: S) -> Result<()>
+        where
+            S: Subscriber<K, G> + 'static,
+        {
+            self.register(event_name, subscriber, true);
+
+            Ok(())
         }
 
-        /// Adds the provided [Subscriber] to the subscriber list of the provided [String]
-        pub fn subscribe<N, S>(&mut self, event_name: N, subscriber: S) -> Result<()>
+        fn register<S>(&mut self, event_name: K, subscriber: S, once: bool)
         where
-            N: Into<String>,
-            S: Subscriber + 'static
+            S: Subscriber<K, G> + 'static,
         {
-            let event = event_name.into();
+            let registration = Registration {
+                subscriber: Box::new(subscriber),
+                once,
+            };
 
-            match self.event_subscribers.get(&event) {
+            match self.event_subscribers.get(&event_name) {
                 Some(_) => {
                     // If event is already registered to the map
                     self.event_subscribers
-                        .get_mut(&event)
+                        .get_mut(&event_name)
                         .unwrap()
-                        .push(Box::new(subscriber));
+                        .push(registration);
                 }
                 None => {
                     // If event is not registered to map
                     self.event_subscribers
-                        .insert(event.into(), vec![Box::new(subscriber)]);
+                        .insert(event_name, vec![registration]);
                 }
             }
+        }
+
+        pub fn unsubscribe<S>(&mut self, event_name: K, subscriber: S) -> Result<()>
+        where
+            S: Subscriber<K, G> + 'static,
+        {
+            let target = subscriber.type_id();
+
+            if let Some(subscribers) = self.event_subscribers.get_mut(&event_name) {
+                subscribers.retain(|registration| (*registration.subscriber).type_id() != target);
+            }
 
             Ok(())
         }
 
-        pub fn unsubscribe<N, S>(&mut self, event_name: N, subscriber: S) -> Result<()>
+        /// Adds the provided [Subscriber] to the listener list of the provided group, so it receives every [Event] tagged with that group regardless of its name
+        pub fn subscribe_group<S>(&mut self, group: G, subscriber: S) -> Result<()>
         where
-            N: Into<String>,
-            S: Subscriber + 'static,
+            S: Subscriber<K, G> + 'static,
         {
-            // self.event_subscribers.get_mut(&event_name).unwrap().remove(self.event_subscribers.iter().position(|event_subscriber| event_subscriber.type_id() == subscriber.type_id()).unwrap());
-
-            // Dumb if statements
-            // TODO: Optimize this
-            if let Some(subscribers) = self.event_subscribers.get_mut(&event_name.into()) {
-                if let Some(index) =
-                    subscribers
-                        .iter()
-                        .position(|event_subscriber| {
-                            event_subscriber.type_id() == subscriber.type_id()
-                        })
-                {
-                    subscribers.remove(index);
+            let registration = Registration {
+                subscriber: Box::new(subscriber),
+                once: false,
+            };
+
+            match self.group_subscribers.get(&group) {
+                Some(_) => {
+                    // If group is already registered to the map
+                    self.group_subscribers
+                        .get_mut(&group)
+                        .unwrap()
+                        .push(registration);
+                }
+                None => {
+                    // If group is not registered to map
+                    self.group_subscribers
+                        .insert(group, vec![registration]);
                 }
             }
 
             Ok(())
         }
 
-        /// Adds all [Subscriber] objects in the provided vec to the subscriber list of the provided [String]
-        pub fn subscribe_all<N, S>(&mut self, event_name: &N, subscribers: Vec<S>) -> Result<()>
+        /// Returns whether any [Subscriber] is currently registered for the given key, without otherwise touching the bus
+        pub fn has_subscriber(&self, event_name: &K) -> bool {
+            self.event_subscribers
+                .get(event_name)
+                .is_some_and(|subscribers| !subscribers.is_empty())
+        }
+
+        /// Removes every [Subscriber] registered for the given key
+        pub fn clear(&mut self, event_name: &K) {
+            self.event_subscribers.remove(event_name);
+        }
+
+        /// Removes every [Subscriber], for every key and every group
+        pub fn clear_all(&mut self) {
+            self.event_subscribers.clear();
+            self.group_subscribers.clear();
+        }
+
+        /// Adds all [Subscriber] objects in the provided vec to the subscriber list of the provided key
+        pub fn subscribe_all<S>(&mut self, event_name: &K, subscribers: Vec<S>) -> Result<()>
         where
-            N: Into<String> + Clone,
-            S: Subscriber + 'static
+            S: Subscriber<K, G> + 'static,
+            K: std::fmt::Debug,
         {
             for subscriber in subscribers {
-                self.subscribe(event_name.clone(), subscriber)
-                    .expect(&format!(
-                        "Error when subscribing to event: {}",
-                        event_name.clone().into() // Kinda dumb but iteration sucks
-                    ))
+                self.subscribe(event_name.clone(), subscriber).unwrap_or_else(|_| {
+                    panic!("Error when subscribing to event: {:?}", event_name) // Kinda dumb but iteration sucks
+                })
             }
 
             Ok(())
         }
 
-        pub fn unsubscribe_all<N, S>(&mut self, event_name: &N, subscribers: Vec<S>) -> Result<()>
+        pub fn unsubscribe_all<S>(&mut self, event_name: &K, subscribers: Vec<S>) -> Result<()>
         where
-            N: Into<String> + Clone,
-            S: Subscriber + 'static
+            S: Subscriber<K, G> + 'static,
+            K: std::fmt::Debug,
         {
             for subscriber in subscribers {
-                self.unsubscribe(event_name.clone(), subscriber)
-                    .expect(&format!(
-                        "Error when unsubscribing from event: {}",
-                        event_name.clone().into() // Kinda dumb but iteration sucks
-                    ))
+                self.unsubscribe(event_name.clone(), subscriber).unwrap_or_else(|_| {
+                    panic!("Error when unsubscribing from event: {:?}", event_name) // Kinda dumb but iteration sucks
+                })
             }
 
             Ok(())
         }
 
-        /// Posts an [Event] to all [Subscriber]s that are listening on that event name
-        pub fn post(&mut self, event: Event)
-        {
+        /// Posts an [Event] to all [Subscriber]s that are listening on that event's key, as well as every [Subscriber] registered on the event's group (if any) via [EventBus::subscribe_group]
+        ///
+        /// A failing [Subscriber] doesn't stop delivery: every subscriber is called, and the indices and errors of the ones that failed are collected and returned together in a [PostError]. Subscribers registered with [EventBus::subscribe_once] whose call succeeded are dropped once the dispatch loop that fired them is done; a one-shot whose call failed stays registered.
+        pub fn post(&mut self, event: Event<K, G>) -> std::result::Result<(), PostError> {
+            let mut errors = Vec::new();
+            let mut index = 0;
+
             if let Some(subscribers) = self.event_subscribers.get_mut(&event.name) {
-                for subscriber in subscribers {
-                    subscriber
-                        .call(&event)
-                        .expect(&format!("Error when posting event: {}", event.name));
+                index = dispatch(subscribers, &event, &mut errors, index);
+            }
+
+            if let Some(group) = &event.group {
+                if let Some(subscribers) = self.group_subscribers.get_mut(group) {
+                    dispatch(subscribers, &event, &mut errors, index);
+                }
+            }
+
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(PostError(errors))
+            }
+        }
+    }
+
+    /// Calls every [Registration] in `subscribers` with `event`, collecting errors into `errors` (indexed starting at `start_index`), then drops any one-shot [Registration] that fired successfully
+    ///
+    /// Returns `start_index` plus the number of subscribers just dispatched, so a second call dispatching a different list of subscribers for the same [Event] keeps error indices unique
+    fn dispatch<K: 'static, G: 'static>(
+        subscribers: &mut Vec<Registration<K, G>>,
+        event: &Event<K, G>,
+        errors: &mut Vec<(usize, std::io::Error)>,
+        start_index: usize,
+    ) -> usize {
+        let dispatched = subscribers.len();
+        let mut fired = Vec::new();
+
+        for (position, registration) in subscribers.iter_mut().enumerate() {
+            match registration.subscriber.call(event) {
+                Ok(()) if registration.once => fired.push(position),
+                Ok(()) => {}
+                Err(error) => errors.push((start_index + position, error)),
+            }
+        }
+
+        for position in fired.into_iter().rev() {
+            subscribers.remove(position);
+        }
+
+        start_index + dispatched
+    }
+
+    /// Error returned by [EventBus::post] listing the index and error of every [Subscriber] that failed
+    #[derive(Debug)]
+    pub struct PostError(pub Vec<(usize, std::io::Error)>);
+
+    impl std::fmt::Display for PostError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} subscriber(s) failed: ", self.0.len())?;
+            for (i, (index, error)) in self.0.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "[{index}] {error}")?;
+            }
+            Ok(())
+        }
+    }
+
+    impl std::error::Error for PostError {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::{cell::RefCell, io::Error, rc::Rc};
+
+        fn fails(event: &Event<&'static str>) -> Result<()> {
+            let _ = event;
+            Err(Error::other("nope"))
+        }
+
+        #[test]
+        fn post_reports_the_index_of_a_failing_subscriber_among_passing_ones() {
+            let mut bus = EventBus::new();
+            bus.subscribe("tick", |_: &Event<&'static str>| Ok(())).unwrap();
+            bus.subscribe("tick", fails).unwrap();
+            bus.subscribe("tick", |_: &Event<&'static str>| Ok(())).unwrap();
+
+            let error = bus.post(Event::new("tick", 0)).unwrap_err();
+
+            assert_eq!(error.0.len(), 1);
+            assert_eq!(error.0[0].0, 1);
+        }
+
+        #[test]
+        fn post_numbers_group_subscriber_errors_after_name_subscriber_errors() {
+            let mut bus = EventBus::new();
+            bus.subscribe("tick", fails).unwrap();
+            bus.subscribe_group("clock", fails).unwrap();
+
+            let error = bus.post(Event::with_group("tick", "clock", 0)).unwrap_err();
+
+            let indices: Vec<usize> = error.0.iter().map(|(index, _)| *index).collect();
+            assert_eq!(indices, vec![0, 1]);
+        }
+
+        #[test]
+        fn subscribe_once_fires_exactly_once() {
+            let mut bus = EventBus::new();
+            let count = Rc::new(RefCell::new(0));
+
+            let counted = {
+                let count = Rc::clone(&count);
+                move |_: &Event<&'static str>| {
+                    *count.borrow_mut() += 1;
+                    Ok(())
+                }
+            };
+            bus.subscribe_once("tick", counted).unwrap();
+
+            bus.post(Event::new("tick", 0)).unwrap();
+            bus.post(Event::new("tick", 0)).unwrap();
+
+            assert_eq!(*count.borrow(), 1);
+        }
+
+        #[test]
+        fn unsubscribe_removes_every_matching_registration() {
+            let mut bus = EventBus::new();
+            bus.subscribe("tick", fails).unwrap();
+            bus.subscribe("tick", fails).unwrap();
+
+            bus.unsubscribe("tick", fails).unwrap();
+
+            assert!(!bus.has_subscriber(&"tick"));
+        }
+    }
+}
+
+/// A discrete-event-simulation layer on top of [event_bus]
+///
+/// A [timeline::Timeline] splits events into past (already posted, kept so the cursor can [rewind](timeline::Timeline::rewind) over them) and future (scheduled but not yet posted). This turns the bus into a reproducible simulator: the same sequence of scheduled events always posts in the same order, which is useful for testing event-driven logic deterministically.
+pub mod timeline {
+    use super::event_bus::{Event, EventBus, PostError};
+    use std::{cell::RefCell, hash::Hash, rc::Rc};
+
+    /// A point in time on a [Timeline]; events scheduled for the same tick are posted in the order they were scheduled
+    pub type Tick = u64;
+
+    /// A closure that produces the [Event] to post for one scheduled slot, called fresh on every [Timeline::advance] (and again on replay after a [Timeline::rewind])
+    pub type Producer<K> = Box<dyn FnMut() -> Event<K>>;
+
+    /// A shared, interior-mutable queue of not-yet-scheduled `(tick, producer)` pairs, as handed out by [Timeline::insertions]
+    pub type Insertions<K> = Rc<RefCell<Vec<(Tick, Producer<K>)>>>;
+
+    /// A reproducible, replayable sequence of [Event]s posted through an [EventBus]
+    ///
+    /// [Timeline::schedule] inserts a producer into the future; [Timeline::advance] calls the next one in tick order, posts the [Event] it produces through the bus, and moves the cursor forward; [Timeline::rewind] moves the cursor back so the same deterministic sequence can be re-run. [Timeline::insertions] hands out a shared buffer a [Subscriber](super::event_bus::Subscriber) invoked during `advance` can schedule further future events into, merged in on the next step without disturbing history already executed.
+    pub struct Timeline<K> {
+        events: Vec<(Tick, Producer<K>)>,
+        cursor: usize,
+        insertions: Insertions<K>,
+    }
+
+    /////////////////////////////////////////////////////////////////////////////
+    // Type implementation
+    /////////////////////////////////////////////////////////////////////////////
+    impl<K: Eq + Hash + Clone + 'static> Default for Timeline<K> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<K: Eq + Hash + Clone + 'static> Timeline<K> {
+        /// Creates an empty [Timeline] with the cursor at the start
+        pub fn new() -> Self {
+            Timeline {
+                events: Vec::new(),
+                cursor: 0,
+                insertions: Rc::new(RefCell::new(Vec::new())),
+            }
+        }
+
+        /// A handle a [Subscriber](super::event_bus::Subscriber) can clone and hold on to so it can [schedule](Timeline::schedule)-like push further events from inside [Timeline::advance]
+        pub fn insertions(&self) -> Insertions<K> {
+            Rc::clone(&self.insertions)
+        }
+
+        /// Schedules `event` to be produced and posted at `tick`
+        ///
+        /// `event` is a producer rather than an [Event] directly so the same tick can be replayed after a [Timeline::rewind] without the original [Event] having been consumed by [EventBus::post]
+        pub fn schedule<F>(&mut self, tick: Tick, event: F)
+        where
+            F: FnMut() -> Event<K> + 'static,
+        {
+            let position = self.events[self.cursor..]
+                .partition_point(|(existing_tick, _)| *existing_tick <= tick)
+                + self.cursor;
+
+            self.events.insert(position, (tick, Box::new(event)));
+        }
+
+        /// Produces and posts the next scheduled [Event] through `bus`, advancing the cursor, after first merging in anything [scheduled](Timeline::schedule) by a subscriber during the previous step
+        ///
+        /// Returns [None] once there are no more future events
+        pub fn advance(&mut self, bus: &mut EventBus<K>) -> Option<std::result::Result<(), PostError>> {
+            let pending: Vec<_> = self.insertions.borrow_mut().drain(..).collect();
+
+            for (tick, event) in pending {
+                self.schedule(tick, event);
+            }
+
+            let (_, producer) = self.events.get_mut(self.cursor)?;
+            let event = producer();
+            self.cursor += 1;
+
+            Some(bus.post(event))
+        }
+
+        /// Moves the cursor back by one step, so the event at that position will be produced and posted again on the next [Timeline::advance] without discarding it from history
+        pub fn rewind(&mut self) {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::{cell::RefCell, rc::Rc};
+
+        #[test]
+        fn advance_posts_scheduled_events_in_tick_order() {
+            let mut timeline = Timeline::new();
+            let mut bus = EventBus::new();
+            let seen = Rc::new(RefCell::new(Vec::new()));
+
+            let record = |seen: Rc<RefCell<Vec<&'static str>>>| {
+                move |event: &Event<&'static str>| {
+                    seen.borrow_mut().push(*event.data::<&'static str>());
+                    Ok(())
                 }
+            };
+            bus.subscribe("tick", record(Rc::clone(&seen))).unwrap();
+
+            timeline.schedule(5, || Event::new("tick", "second"));
+            timeline.schedule(1, || Event::new("tick", "first"));
+
+            timeline.advance(&mut bus).unwrap().unwrap();
+            timeline.advance(&mut bus).unwrap().unwrap();
+
+            assert!(timeline.advance(&mut bus).is_none());
+            assert_eq!(*seen.borrow(), vec!["first", "second"]);
+        }
+
+        #[test]
+        fn rewind_replays_the_same_slot() {
+            let mut timeline = Timeline::new();
+            let mut bus = EventBus::new();
+            let calls = Rc::new(RefCell::new(0));
+
+            let produced = Rc::clone(&calls);
+            timeline.schedule(0, move || {
+                *produced.borrow_mut() += 1;
+                Event::new("tick", ())
+            });
+
+            timeline.advance(&mut bus).unwrap().unwrap();
+            assert_eq!(*calls.borrow(), 1);
+            assert!(timeline.advance(&mut bus).is_none());
+
+            timeline.rewind();
+            timeline.advance(&mut bus).unwrap().unwrap();
+            assert_eq!(*calls.borrow(), 2);
+        }
+    }
+}
+
+/// Async variant of [event_bus], backed by channels instead of direct `Subscriber` calls
+///
+/// Where [event_bus::EventBus::post] blocks the caller until every subscriber finishes, [AsyncEventBus::post] just fans the event out to a sender per subscription and returns immediately; subscribers drive an [EventStream] as a [Stream] on whatever task they like. Data therefore has to be `Send + Sync` to cross task boundaries, so this lives behind the `async` feature flag rather than replacing [event_bus] outright.
+#[cfg(feature = "async")]
+pub mod async_event_bus {
+    use futures::Stream;
+    use std::{
+        any::Any,
+        collections::HashMap,
+        hash::Hash,
+        pin::Pin,
+        sync::Arc,
+        task::{Context, Poll},
+    };
+    use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+    /// Event payload for the [AsyncEventBus]; `data` must be `Send + Sync` since a posted event may be picked up on a different task than the one that posted it
+    pub struct Event<K> {
+        name: K,
+        pub data: Box<dyn Any + Send + Sync>,
+    }
+
+    /////////////////////////////////////////////////////////////////////////////
+    // Type implementation
+    /////////////////////////////////////////////////////////////////////////////
+    impl<K> Event<K> {
+        /// Creates a new event with the provided name and data
+        pub fn new<D>(name: K, data: D) -> Self
+        where
+            D: Send + Sync + 'static,
+        {
+            Event {
+                name,
+                data: Box::new(data),
+            }
+        }
+
+        pub fn data<T: 'static>(&self) -> &T {
+            self.try_data().unwrap()
+        }
+
+        /// Same as [Event::data], but returns [None] instead of panicking if `T` doesn't match
+        pub fn try_data<T: 'static>(&self) -> Option<&T> {
+            self.data.downcast_ref::<T>()
+        }
+    }
+
+    /// A [Stream] of events handed out by [AsyncEventBus::subscribe_stream] for a single subscription
+    pub struct EventStream<K> {
+        receiver: UnboundedReceiver<Arc<Event<K>>>,
+    }
+
+    impl<K> Stream for EventStream<K> {
+        type Item = Arc<Event<K>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.receiver.poll_recv(cx)
+        }
+    }
+
+    /// Async [EventBus](crate::event_bus::EventBus) variant: keys events the same way, but dispatches over channels instead of calling subscribers directly
+    pub struct AsyncEventBus<K> {
+        senders: HashMap<K, Vec<UnboundedSender<Arc<Event<K>>>>>,
+    }
+
+    impl<K: Eq + Hash + Clone> Default for AsyncEventBus<K> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////////////
+    // Type implementation
+    /////////////////////////////////////////////////////////////////////////////
+    impl<K: Eq + Hash + Clone> AsyncEventBus<K> {
+        /// Creates a new [AsyncEventBus] with no subscriptions
+        pub fn new() -> Self {
+            AsyncEventBus {
+                senders: HashMap::new(),
+            }
+        }
+
+        /// Hands out an [EventStream] that will yield every future [Event] posted under the provided key
+        pub fn subscribe_stream(&mut self, event_name: K) -> EventStream<K> {
+            let (sender, receiver) = mpsc::unbounded_channel();
+
+            self.senders.entry(event_name).or_default().push(sender);
+
+            EventStream { receiver }
+        }
+
+        /// Fans the event out to every live subscriber of its key and returns immediately, without waiting for any of them to handle it
+        ///
+        /// Sends only fail once their [EventStream] has been dropped, so a failed send is also how we notice a subscription is gone and prune it from the list
+        pub fn post(&mut self, event: Event<K>) {
+            let event = Arc::new(event);
+
+            if let Some(senders) = self.senders.get_mut(&event.name) {
+                senders.retain(|sender| sender.send(Arc::clone(&event)).is_ok());
             }
         }
     }
@@ -171,9 +636,9 @@ fn main() {
         }
     }
 
-    impl Subscriber for Ticker {
+    impl Subscriber<&'static str> for Ticker {
 
-        fn call(&mut self, event: &Event) -> Result<()> {
+        fn call(&mut self, event: &Event<&'static str>) -> Result<()> {
             println!("Tock!, {}", event.data::<i32>());
 
             Ok(())
@@ -186,10 +651,10 @@ fn main() {
 
     event_bus.subscribe("TickEvent", Ticker::new()).unwrap();
 
-    event_bus.post(event);
+    event_bus.post(event).unwrap();
 
     for i in 0..10 {
-        event_bus.post(Event::new("TickEvent", i));
+        event_bus.post(Event::new("TickEvent", i)).unwrap();
         thread::sleep(Duration::from_millis(100));
     }
 }